@@ -0,0 +1,70 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The spare bit set for scalars that aren't a lowercase ASCII letter, e.g.
+/// digits, punctuation, or non-Latin scripts.
+const SPARE_BIT: u32 = 1 << 31;
+
+/// Computes a 32-bit letter-presence bitmask over grapheme clusters: bit
+/// `c - 'a'` is set for each lowercase ASCII letter that appears, and any
+/// other scalar folds into a spare high bit.
+///
+/// Each cluster is folded over *all* of its scalars, not just the first,
+/// since NFKD normalization can expand a single cluster into several
+/// letters (e.g. `"ﬁ"` → `"fi"`). Callers should pass normalized clusters
+/// where available so an accented letter like `"é"` sets the same bit as
+/// its base letter `'e'` instead of falling into the spare bit.
+///
+/// This is a quick-reject prefilter for dictionary matching: a dictionary
+/// word can only occur within a sentence word if `dict_mask & word_mask ==
+/// dict_mask`, i.e. the dictionary word's letters are a subset of the word's
+/// letters. Checking the masks first lets the expensive exact comparison be
+/// skipped entirely for the common case, without ever producing a false
+/// negative.
+pub(crate) fn letter_mask<'a>(clusters: impl IntoIterator<Item = &'a str>) -> u32 {
+	clusters.into_iter().flat_map(str::chars).fold(0u32, |mask, c| mask | char_bit(c))
+}
+
+/// Computes the letter-presence bitmask for a whole word, splitting it into
+/// grapheme clusters first. Intended for precomputing a dictionary word's
+/// mask once so repeated lookups against a `Sentence` can reuse it.
+pub(crate) fn letter_mask_str(word: &str) -> u32 {
+	letter_mask(word.graphemes(true))
+}
+
+/// Computes the bitmask contribution of a single scalar value.
+fn char_bit(c: char) -> u32 {
+	if c.is_ascii_lowercase() {
+		1 << (c as u32 - 'a' as u32)
+	} else {
+		SPARE_BIT
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sets_one_bit_per_letter() {
+		assert_eq!(letter_mask_str("cab"), (1 << 0) | (1 << 1) | (1 << 2));
+	}
+
+	#[test]
+	fn non_letter_scalars_fold_into_spare_bit() {
+		assert_eq!(letter_mask_str("a-1"), (1 << 0) | SPARE_BIT);
+	}
+
+	#[test]
+	fn folds_over_every_scalar_in_a_cluster_not_just_the_first() {
+		// A cluster can normalize to more than one scalar (e.g. "ﬁ" -> "fi");
+		// both letters must be reflected in the mask.
+		assert_eq!(letter_mask(["fi"]), (1 << 5) | (1 << 8));
+	}
+
+	#[test]
+	fn dictionary_mask_is_subset_of_matching_word_mask() {
+		let dict_mask = letter_mask_str("hello");
+		let word_mask = letter_mask_str("hello world");
+		assert_eq!(dict_mask & word_mask, dict_mask);
+	}
+}
@@ -1,6 +1,12 @@
 use std::fmt;
 
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
 use crate::confusables::Confusable;
+use crate::mask::letter_mask;
+use crate::separator::{WhitespaceSeparator, WordSeparator};
 
 #[napi]
 #[derive(PartialEq)]
@@ -29,45 +35,112 @@ impl Boundary {
 
 #[napi]
 pub struct Sentence {
-	// TODO: checked is useful, but it could be even more useful by having a vector of spans which split up as needed.
-	// For example, if "drowned" is marked in "Steve drowned in lava", upon marking, the regions would become from
-	// [0..=21] to [0..=6] ("Steve ") and [13..=21] (" in lava"), where [7..=12] ("drowned") is removed. However, an
-	// improved algorithm would include the spaces (trimming sideways) so the ranges become [0..=5] ("Steve") and
-	// [14..=21] ("in lava"), which reduces the amount of checks. However, `checked` must stay untrimmed or else when
-	// the words are censored, it includes the spaces ("Steve*********in lava") which is suboptimal compared to the
-	// opposite ("Steve ******* in lava").
-	/// A vector of booleans that represent whether the character at the same
-	/// index has been checked by a `Word`.
+	/// A vector of booleans that represent whether the grapheme cluster at the
+	/// same index has been checked by a `Word`.
 	pub checked: Vec<bool>,
 	/// A vector of `Boundary` that represent the boundaries of the words in the
 	/// sentence.
 	pub boundaries: Vec<Boundary>,
-	/// A vector of characters that represent the sanitized contents of the
-	/// sentence.
-	pub(crate) contents: Vec<char>,
-	/// A vector of indexes that represent the start and end of each word in the
-	/// sentence.
+	/// A vector of extended grapheme clusters that represent the sanitized
+	/// contents of the sentence. Using clusters rather than `char`s keeps
+	/// combining marks, ZWJ emoji sequences, and regional-indicator pairs
+	/// intact as a single user-perceived character.
+	pub(crate) contents: Vec<Box<str>>,
+	/// A vector of indexes that represent the start and end cluster of each
+	/// word in the sentence.
 	pub(crate) indexes: Vec<(usize, usize)>,
+	/// An auxiliary de-punctuated view of the sentence produced by the
+	/// `WordSeparator` used to build it, if any. This lets a matcher also
+	/// test the joined contents directly, defeating separator-based evasion
+	/// such as `b.a.d` or `h-e-l-l-o`.
+	pub(crate) joined: Option<Box<str>>,
+	/// Each cluster of `contents` normalized via Unicode NFKD with combining
+	/// marks (general category Mark: Mn, Mc and Me) stripped, so compatibility forms and stacked
+	/// diacritics collapse to their base letters, e.g. `"ﬁ"` → `"fi"` and
+	/// zalgo text reduces to plain letters. Indexed 1:1 with `contents`, so
+	/// `checked`/censoring can still target the untouched original clusters.
+	/// `None` when the sentence was built with normalization disabled via
+	/// `Sentence::with_options`, in which case matchers should fall back to
+	/// `contents` directly.
+	pub(crate) normalized: Option<Vec<Box<str>>>,
+	/// A letter-presence bitmask for each word, indexed 1:1 with `indexes`,
+	/// computed once so repeated dictionary lookups can reuse it as a
+	/// quick-reject prefilter instead of rescanning the word's clusters.
+	/// See `mask::letter_mask`.
+	pub(crate) word_masks: Vec<u32>,
+	/// Live spans of the sentence not yet covered by a match, trimmed
+	/// sideways of leading/trailing non-word clusters so a matcher only
+	/// rescans the shrinking unmatched remainder instead of the whole
+	/// sentence. `checked` stays untrimmed and is the source of truth for
+	/// censoring; `spans` is the source of truth for what's left to scan.
+	/// See `Sentence::mark`.
+	pub(crate) spans: Vec<(usize, usize)>,
+}
+
+/// Normalizes a single grapheme cluster via NFKD and strips combining marks
+/// (Unicode general category Mark: Mn, Mc and Me), collapsing it to its base
+/// letter(s).
+fn normalize_cluster(cluster: &str) -> Box<str> {
+	cluster.nfkd().filter(|c| !unicode_normalization::char::is_combining_mark(*c)).collect::<String>().into()
 }
 
 #[napi]
 impl Sentence {
 	#[napi(constructor)]
 	pub fn new(sentence: String) -> Self {
+		Self::with_separator(sentence, WhitespaceSeparator)
+	}
+
+	#[napi(getter)]
+	pub fn length(&self) -> u32 {
+		self.contents.len() as u32
+	}
+
+	#[napi(js_name = "toString")]
+	pub fn js_to_string(&self) -> String {
+		self.to_string()
+	}
+
+	#[napi(js_name = "censor")]
+	pub fn js_censor(&self, mask: String) -> String {
+		self.censor(mask.chars().next().unwrap_or('*'))
+	}
+}
+
+impl Sentence {
+	/// Builds a `Sentence`, classifying boundaries with a custom
+	/// `WordSeparator` instead of the default whitespace/control splitting
+	/// used by `Sentence::new`. This lets callers defeat punctuation-based
+	/// obfuscation, such as `b.a.d` or `h-e-l-l-o`, by swapping in
+	/// `PunctuationSeparator`. Equivalent to `with_options` with
+	/// normalization enabled.
+	pub fn with_separator<S: WordSeparator>(sentence: String, separator: S) -> Self {
+		Self::with_options(sentence, separator, true)
+	}
+
+	/// Builds a `Sentence` like `with_separator`, but lets callers opt out of
+	/// NFKD normalization and combining-mark stripping when they need
+	/// byte-faithful output, e.g. `normalize: false`.
+	pub fn with_options<S: WordSeparator>(sentence: String, separator: S, normalize: bool) -> Self {
 		let sentence = sentence.replace_confusables().to_lowercase();
-		let mut checked: Vec<bool> = Vec::with_capacity(sentence.len());
-		let mut boundaries: Vec<Boundary> = Vec::with_capacity(sentence.len());
-		let mut contents: Vec<char> = Vec::with_capacity(sentence.len());
-		let mut indexes: Vec<(usize, usize)> = Vec::with_capacity(sentence.len());
+		let clusters: Vec<&str> = sentence.graphemes(true).collect();
+
+		let mut checked: Vec<bool> = Vec::with_capacity(clusters.len());
+		let mut boundaries: Vec<Boundary> = Vec::with_capacity(clusters.len());
+		let mut contents: Vec<Box<str>> = Vec::with_capacity(clusters.len());
+		let mut indexes: Vec<(usize, usize)> = Vec::with_capacity(clusters.len());
+		let mut normalized: Vec<Box<str>> = Vec::with_capacity(if normalize { clusters.len() } else { 0 });
+		let mut word_masks: Vec<u32> = Vec::new();
+		let mut word_mask: u32 = 0;
 
-		let mut chars = sentence.chars().peekable();
+		let mut clusters = clusters.into_iter().peekable();
 		// TODO: Rewrite this loop to a nested loop for improved efficiency, code
 		// readability, and reduce code duplication.
-		while let Some(c) = chars.next() {
-			let mut boundary = if c.is_whitespace() || c.is_control() {
+		while let Some(cluster) = clusters.next() {
+			let mut boundary = if separator.is_separator(cluster) {
 				Boundary::NoContent
-			} else if let Some(c) = boundaries.last() {
-				if c.is_word() {
+			} else if let Some(b) = boundaries.last() {
+				if b.is_word() {
 					Boundary::Word
 				} else {
 					Boundary::Start
@@ -80,20 +153,20 @@ impl Sentence {
 				let start = contents.len();
 				indexes.push((start, start));
 
-				// If the next character is a whitespace or control character, the boundary is
-				// mixed as this character is both the start and the end of a word.
-				if let Some(c) = chars.peek() {
-					if c.is_whitespace() || c.is_control() {
+				// If the next cluster is a separator, the boundary is
+				// mixed as this cluster is both the start and the end of a word.
+				if let Some(next) = clusters.peek() {
+					if separator.is_separator(next) {
 						boundary = Boundary::Mixed;
 					}
 				} else {
 					boundary = Boundary::Mixed;
 				}
 			} else if boundary == Boundary::Word {
-				// If the next character is a whitespace or control character, the boundary is
-				// end as this character is the end of a word.
-				if let Some(c) = chars.peek() {
-					if c.is_whitespace() || c.is_control() {
+				// If the next cluster is a separator, the boundary is
+				// end as this cluster is the end of a word.
+				if let Some(next) = clusters.peek() {
+					if separator.is_separator(next) {
 						boundary = Boundary::End;
 					}
 				} else {
@@ -101,27 +174,255 @@ impl Sentence {
 				}
 			}
 
+			// The mask must be derived from the normalized cluster, not the raw
+			// one, or an accented letter like "é" falls into the spare bit
+			// instead of the same bit as its base letter "e", defeating the
+			// diacritic stripping above as a quick-reject false negative.
+			let normalized_cluster = normalize.then(|| normalize_cluster(cluster));
+			let mask_source = normalized_cluster.as_deref().unwrap_or(cluster);
+
+			match boundary {
+				Boundary::Start => word_mask = letter_mask([mask_source]),
+				Boundary::Word => word_mask |= letter_mask([mask_source]),
+				Boundary::Mixed => word_masks.push(letter_mask([mask_source])),
+				Boundary::End => {
+					word_mask |= letter_mask([mask_source]);
+					word_masks.push(word_mask);
+					if let Some(index) = indexes.last_mut() {
+						index.1 = contents.len();
+					}
+				}
+				Boundary::NoContent => {}
+			}
+
 			checked.push(false);
 			boundaries.push(boundary);
-			contents.push(c);
+			if let Some(normalized_cluster) = normalized_cluster {
+				normalized.push(normalized_cluster);
+			}
+			contents.push(cluster.into());
 		}
 
-		Self { checked, boundaries, contents, indexes }
+		// Build the joined view from the normalized clusters too, so
+		// punctuation- and diacritic-based obfuscation compose, e.g.
+		// "h.é.l.l.o" joins to "hello" rather than "héllo".
+		let joined = separator.joined_view(if normalize { &normalized } else { &contents });
+		let normalized = normalize.then_some(normalized);
+		let spans = if contents.is_empty() { Vec::new() } else { Self::trim(&boundaries, 0, contents.len() - 1).into_iter().collect() };
+
+		Self { checked, boundaries, contents, indexes, joined, normalized, word_masks, spans }
 	}
 
-	#[napi(getter)]
-	pub fn length(&self) -> u32 {
-		self.contents.len() as u32
+	/// Marks clusters `[start..=end]` as matched: flips their `checked` bits
+	/// and splits whichever live span in `spans` currently contains the
+	/// range into its left and right remainders. Remainders are trimmed
+	/// sideways of leading/trailing non-word clusters purely for matching
+	/// purposes, eliminating up-front boundary separators the way a dedent
+	/// routine peels spaces, but `checked` keeps the untrimmed range so
+	/// censoring still yields e.g. `"Steve ******* in lava"` rather than
+	/// `"Steve*******in lava"`.
+	pub(crate) fn mark(&mut self, start: usize, end: usize) {
+		for checked in &mut self.checked[start..=end] {
+			*checked = true;
+		}
+
+		let Some(index) = self.spans.iter().position(|&(a, b)| a <= start && end <= b) else {
+			return;
+		};
+		let (a, b) = self.spans.remove(index);
+
+		if end < b {
+			if let Some(span) = Self::trim(&self.boundaries, end + 1, b) {
+				self.spans.insert(index, span);
+			}
+		}
+		if a < start {
+			if let Some(span) = Self::trim(&self.boundaries, a, start - 1) {
+				self.spans.insert(index, span);
+			}
+		}
 	}
 
-	#[napi(js_name = "toString")]
-	pub fn js_to_string(&self) -> String {
-		self.to_string()
+	/// Trims leading/trailing non-word clusters off `[start..=end]`,
+	/// returning `None` if nothing but separators remain in the range.
+	fn trim(boundaries: &[Boundary], start: usize, end: usize) -> Option<(usize, usize)> {
+		let start = (start..=end).find(|&i| boundaries[i].is_word())?;
+		let end = (start..=end).rev().find(|&i| boundaries[i].is_word())?;
+		Some((start, end))
+	}
+
+	/// Returns the sanitized contents of the sentence with every `checked`
+	/// cluster replaced by `mask`. The number of mask characters emitted per
+	/// cluster is the cluster's Unicode display width rather than its scalar
+	/// count, so a wide CJK ideograph is replaced by two masks, while
+	/// zero-width joiners and combining marks contribute none. This keeps the
+	/// censored output visually aligned with the original text.
+	///
+	/// Width is summed per-scalar across the whole cluster, so a multi-scalar
+	/// ZWJ sequence (e.g. a compound family emoji) emits one mask per wide
+	/// base emoji it's composed of, not a single mask for the whole visual
+	/// glyph. This is intentional: it's the same per-scalar summation that
+	/// makes CJK ideographs censor to two masks, applied consistently.
+	pub fn censor(&self, mask: char) -> String {
+		let mut output = String::with_capacity(self.contents.len());
+
+		for (cluster, checked) in self.contents.iter().zip(self.checked.iter()) {
+			if *checked {
+				for _ in 0..cluster.width() {
+					output.push(mask);
+				}
+			} else {
+				output.push_str(cluster);
+			}
+		}
+
+		output
 	}
 }
 
 impl fmt::Display for Sentence {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "{}", self.contents.iter().collect::<String>())
+		write!(f, "{}", self.contents.join(""))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mask::letter_mask_str;
+
+	#[test]
+	fn word_mask_uses_normalized_letters_not_raw_scalars() {
+		// "é" must contribute the same bit as "e", or the quick-reject wrongly
+		// skips a dictionary word that only matches after diacritic stripping.
+		let sentence = Sentence::new("héllo".to_string());
+		let dict_mask = letter_mask_str("hello");
+
+		assert_eq!(sentence.word_masks.len(), 1);
+		assert_eq!(dict_mask & sentence.word_masks[0], dict_mask);
+	}
+
+	#[test]
+	fn mark_splits_span_and_trims_separators_sideways() {
+		let mut sentence = Sentence::new("Steve drowned in lava".to_string());
+		sentence.mark(6, 12);
+
+		assert_eq!(sentence.spans, vec![(0, 4), (14, 20)]);
+		assert!(sentence.checked[6..=12].iter().all(|&c| c));
+		assert!(sentence.checked[..6].iter().all(|&c| !c));
+		assert!(sentence.checked[13..].iter().all(|&c| !c));
+	}
+
+	#[test]
+	fn mark_whole_span_leaves_no_remainder() {
+		let mut sentence = Sentence::new("hello".to_string());
+		let end = sentence.length() as usize - 1;
+		sentence.mark(0, end);
+
+		assert!(sentence.spans.is_empty());
+	}
+
+	#[test]
+	fn mark_drops_remainder_that_is_only_separators() {
+		let mut sentence = Sentence::new("hi ".to_string());
+		sentence.mark(0, 1);
+
+		assert!(sentence.spans.is_empty());
+	}
+
+	#[test]
+	fn censor_masks_wide_clusters_by_display_width() {
+		let mut sentence = Sentence::new("a你b".to_string());
+		sentence.checked[1] = true;
+
+		assert_eq!(sentence.censor('*'), "a**b");
+	}
+
+	#[test]
+	fn censor_leaves_unchecked_clusters_untouched() {
+		let sentence = Sentence::new("a你b".to_string());
+
+		assert_eq!(sentence.censor('*'), "a你b");
+	}
+
+	#[test]
+	fn censor_sums_width_per_scalar_across_a_zwj_cluster() {
+		// The family emoji is one cluster (see zwj_family_emoji_stays_one_cluster)
+		// made of four wide (width 2) base emoji joined by three zero-width
+		// joiners, so censoring it emits 4*2 = 8 masks, not 1 or 4. This is
+		// the same per-scalar width summation that makes a CJK ideograph
+		// censor to two masks, applied consistently to multi-scalar clusters;
+		// it is documented here as intentional rather than incidental.
+		let mut sentence = Sentence::new("👨‍👩‍👧‍👦".to_string());
+		sentence.checked[0] = true;
+
+		assert_eq!(sentence.censor('*'), "*".repeat(8));
+	}
+
+	#[test]
+	fn zwj_family_emoji_stays_one_cluster() {
+		// A ZWJ sequence of four codepoints is a single user-perceived
+		// character and must not be split across several `contents` entries.
+		let sentence = Sentence::new("👨‍👩‍👧‍👦 hi".to_string());
+
+		assert_eq!(sentence.length(), 4);
+		assert_eq!(sentence.contents[0].as_ref(), "👨‍👩‍👧‍👦");
+		assert_eq!(sentence.contents[1].as_ref(), " ");
+		assert_eq!(sentence.contents[2].as_ref(), "h");
+		assert_eq!(sentence.contents[3].as_ref(), "i");
+	}
+
+	#[test]
+	fn regional_indicator_pair_stays_one_cluster() {
+		// "🇺🇸" is two regional-indicator scalars that form one flag cluster.
+		let sentence = Sentence::new("🇺🇸 flag".to_string());
+
+		assert_eq!(sentence.length(), 6);
+		assert_eq!(sentence.contents[0].as_ref(), "🇺🇸");
+	}
+
+	#[test]
+	fn normalize_cluster_expands_ligature_to_base_letters() {
+		assert_eq!(normalize_cluster("ﬁ").as_ref(), "fi");
+	}
+
+	#[test]
+	fn normalize_cluster_strips_single_combining_mark() {
+		assert_eq!(normalize_cluster("e\u{0301}").as_ref(), "e");
+	}
+
+	#[test]
+	fn normalize_cluster_strips_zalgo_combining_marks() {
+		// A base letter buried under a dozen stacked combining marks must
+		// still reduce to the plain letter.
+		let zalgo = format!("e{}", "\u{0301}".repeat(12));
+
+		assert_eq!(normalize_cluster(&zalgo).as_ref(), "e");
+	}
+
+	#[test]
+	fn with_options_normalize_false_leaves_normalized_unset() {
+		let sentence = Sentence::with_options("héllo".to_string(), WhitespaceSeparator, false);
+
+		assert!(sentence.normalized.is_none());
+	}
+
+	#[test]
+	fn with_options_normalize_false_keeps_contents_byte_faithful() {
+		let sentence = Sentence::with_options("héllo".to_string(), WhitespaceSeparator, false);
+
+		assert_eq!(sentence.to_string(), "héllo");
+	}
+
+	#[test]
+	fn combining_mark_stays_attached_to_its_base_letter() {
+		// "é" here is "e" followed by U+0301 COMBINING ACUTE ACCENT: one
+		// grapheme cluster, so it must produce a single `Mixed` boundary
+		// rather than splitting into two clusters/boundaries.
+		let sentence = Sentence::new("e\u{0301}".to_string());
+
+		assert_eq!(sentence.length(), 1);
+		assert_eq!(sentence.contents[0].as_ref(), "e\u{0301}");
+		assert!(sentence.boundaries == vec![Boundary::Mixed]);
 	}
 }
@@ -0,0 +1,105 @@
+/// Classifies whether a grapheme cluster acts as a word separator, letting
+/// callers swap the boundary-detection policy used by `Sentence`.
+pub trait WordSeparator {
+	/// Returns whether `cluster` should be treated as a non-word separator.
+	fn is_separator(&self, cluster: &str) -> bool;
+
+	/// Returns an auxiliary view of `clusters` that a matcher can test in
+	/// addition to the regular word boundaries, or `None` if this separator
+	/// does not produce one.
+	fn joined_view(&self, clusters: &[Box<str>]) -> Option<Box<str>> {
+		let _ = clusters;
+		None
+	}
+}
+
+/// The default separator, splitting on whitespace and control characters,
+/// matching `char::is_whitespace`/`is_control`.
+pub struct WhitespaceSeparator;
+
+impl WordSeparator for WhitespaceSeparator {
+	fn is_separator(&self, cluster: &str) -> bool {
+		cluster.chars().next().is_some_and(|c| c.is_whitespace() || c.is_control())
+	}
+}
+
+/// A separator that also collapses runs of punctuation into
+/// `Boundary::NoContent`, so obfuscated words such as `b.a.d` or `h-e-l-l-o`
+/// split into separate single-letter words instead of a single word. It also exposes a
+/// de-punctuated join of the sentence via `joined_view`, so a matcher can
+/// test the concatenation directly and defeat separator-based evasion.
+pub struct PunctuationSeparator;
+
+impl WordSeparator for PunctuationSeparator {
+	fn is_separator(&self, cluster: &str) -> bool {
+		cluster
+			.chars()
+			.next()
+			.is_some_and(|c| c.is_whitespace() || c.is_control() || c.is_ascii_punctuation())
+	}
+
+	fn joined_view(&self, clusters: &[Box<str>]) -> Option<Box<str>> {
+		let joined: String = clusters
+			.iter()
+			.filter(|cluster| !self.is_separator(cluster))
+			.map(|cluster| cluster.as_ref())
+			.collect();
+
+		Some(joined.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sentence::{Boundary, Sentence};
+
+	#[test]
+	fn punctuation_separator_treats_ascii_punctuation_as_no_content() {
+		let separator = PunctuationSeparator;
+
+		assert!(separator.is_separator("."));
+		assert!(separator.is_separator("-"));
+		assert!(!separator.is_separator("b"));
+	}
+
+	#[test]
+	fn whitespace_separator_does_not_collapse_punctuation() {
+		let separator = WhitespaceSeparator;
+
+		assert!(!separator.is_separator("."));
+		assert!(!separator.is_separator("-"));
+	}
+
+	#[test]
+	fn with_separator_splits_punctuation_obfuscated_word_into_single_clusters() {
+		let sentence = Sentence::with_separator("b.a.d".to_string(), PunctuationSeparator);
+
+		assert_eq!(sentence.boundaries.iter().filter(|b| **b == Boundary::Mixed).count(), 3);
+		assert_eq!(sentence.boundaries.iter().filter(|b| **b == Boundary::NoContent).count(), 2);
+	}
+
+	#[test]
+	fn punctuation_separator_joined_view_strips_separators() {
+		let sentence = Sentence::with_separator("h-e-l-l-o".to_string(), PunctuationSeparator);
+
+		assert_eq!(sentence.joined.as_deref(), Some("hello"));
+	}
+
+	#[test]
+	fn punctuation_separator_joined_view_composes_with_diacritic_normalization() {
+		// The joined view must be built from normalized clusters, or
+		// punctuation-splitting and diacritic-stripping evasions combined
+		// (e.g. "h.é.l.l.o") defeat each other instead of composing.
+		let sentence = Sentence::with_separator("h.é.l.l.o".to_string(), PunctuationSeparator);
+
+		assert_eq!(sentence.joined.as_deref(), Some("hello"));
+	}
+
+	#[test]
+	fn whitespace_separator_produces_no_joined_view() {
+		let sentence = Sentence::new("b.a.d".to_string());
+
+		assert_eq!(sentence.joined, None);
+	}
+}